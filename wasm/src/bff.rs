@@ -31,6 +31,7 @@ pub enum HaltReason {
     MaxSteps = 1,
     UnmatchedBracket = 2,
     NoInstructions = 3,
+    Breakpoint = 4,
 }
 
 /// Result of executing a tape
@@ -122,11 +123,248 @@ pub fn execute_with_head1(tape: &mut [u8], head1_start: usize) -> ExecutionResul
 }
 
 /// Execute BFF program with configurable head1 start and max steps
+///
+/// Thin wrapper over [`execute_with_set`] using [`InstructionSet::paper_default`].
 pub fn execute_with_params(tape: &mut [u8], head1_start: usize, max_steps: u32) -> ExecutionResult {
+    execute_with_set(tape, head1_start, max_steps, &InstructionSet::paper_default())
+}
+
+/// A single BFF operation, independent of which byte value triggers it
+///
+/// Lets an [`InstructionSet`] remap which tape byte performs which
+/// operation (alternate byte layouts) without touching the interpreter
+/// loop itself. The set of operations is fixed to the ten below plus
+/// `Nop`; there are no extra-head or relative-copy operations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Op {
+    Head0Dec = 0,
+    Head0Inc = 1,
+    Head1Dec = 2,
+    Head1Inc = 3,
+    Decrement = 4,
+    Increment = 5,
+    CopyToH1 = 6,
+    CopyToH0 = 7,
+    LoopStart = 8,
+    LoopEnd = 9,
+    Nop = 10,
+}
+
+impl Op {
+    fn from_opcode(opcode: u8) -> Op {
+        match opcode {
+            0 => Op::Head0Dec,
+            1 => Op::Head0Inc,
+            2 => Op::Head1Dec,
+            3 => Op::Head1Inc,
+            4 => Op::Decrement,
+            5 => Op::Increment,
+            6 => Op::CopyToH1,
+            7 => Op::CopyToH0,
+            8 => Op::LoopStart,
+            9 => Op::LoopEnd,
+            _ => Op::Nop,
+        }
+    }
+}
+
+/// Maps every possible tape byte (0-255) to the [`Op`] it performs
+///
+/// The default, returned by [`InstructionSet::paper_default`], matches
+/// the ten opcodes hard-coded in [`instructions`]. Callers that want to
+/// explore alternate BFF dialects build one with [`InstructionSet::from_table`].
+pub struct InstructionSet {
+    ops: [Op; 256],
+}
+
+impl InstructionSet {
+    /// The instruction set from the Turing Soup paper: `< > { } - + . , [ ]`
+    /// map to their usual meaning; every other byte is a no-op data byte.
+    pub fn paper_default() -> Self {
+        let mut ops = [Op::Nop; 256];
+        ops[instructions::HEAD0_DEC as usize] = Op::Head0Dec;
+        ops[instructions::HEAD0_INC as usize] = Op::Head0Inc;
+        ops[instructions::HEAD1_DEC as usize] = Op::Head1Dec;
+        ops[instructions::HEAD1_INC as usize] = Op::Head1Inc;
+        ops[instructions::DECREMENT as usize] = Op::Decrement;
+        ops[instructions::INCREMENT as usize] = Op::Increment;
+        ops[instructions::COPY_TO_H1 as usize] = Op::CopyToH1;
+        ops[instructions::COPY_TO_H0 as usize] = Op::CopyToH0;
+        ops[instructions::LOOP_START as usize] = Op::LoopStart;
+        ops[instructions::LOOP_END as usize] = Op::LoopEnd;
+        InstructionSet { ops }
+    }
+
+    /// Build a custom mapping from a 256-entry byte -> opcode table
+    ///
+    /// `table[byte]` is the [`Op`] discriminant (`0`-`9`) that byte
+    /// performs; `10` or any other value marks it a no-op data byte.
+    pub fn from_table(table: &[u8; 256]) -> Self {
+        let mut ops = [Op::Nop; 256];
+        for (byte, &opcode) in table.iter().enumerate() {
+            ops[byte] = Op::from_opcode(opcode);
+        }
+        InstructionSet { ops }
+    }
+
+    #[inline]
+    fn op(&self, byte: u8) -> Op {
+        self.ops[byte as usize]
+    }
+}
+
+/// Find matching bracket under a custom [`InstructionSet`]
+///
+/// Like [`find_matching_bracket`], but depth-tracks by `set`'s loop ops
+/// rather than the literal `[`/`]` bytes, since a custom set may map
+/// loop brackets to different byte values.
+fn find_matching_bracket_with_set(
+    tape: &[u8],
+    start: usize,
+    direction: i32,
+    set: &InstructionSet,
+) -> Option<usize> {
+    let mut depth = 1i32;
+    let mut pos = start as i32;
+    let size = tape.len() as i32;
+
+    loop {
+        pos += direction;
+
+        if pos < 0 || pos >= size {
+            return None; // Unmatched - hit boundary
+        }
+
+        let op = set.op(tape[pos as usize]);
+
+        if direction > 0 {
+            match op {
+                Op::LoopStart => depth += 1,
+                Op::LoopEnd => depth -= 1,
+                _ => {}
+            }
+        } else {
+            match op {
+                Op::LoopEnd => depth += 1,
+                Op::LoopStart => depth -= 1,
+                _ => {}
+            }
+        }
+
+        if depth == 0 {
+            return Some(pos as usize);
+        }
+    }
+}
+
+/// Execute BFF program with configurable head1 start, max steps, and instruction set
+///
+/// Same semantics as [`execute_with_params`], but which operation each
+/// tape byte performs is looked up in `set` instead of hard-coded, so a
+/// single soup run can experiment with different byte mappings without
+/// recompiling. Only the ten [`Op`] operations can be mapped; `set` cannot
+/// add new operations such as extra heads or relative copies.
+pub fn execute_with_set(
+    tape: &mut [u8],
+    head1_start: usize,
+    max_steps: u32,
+    set: &InstructionSet,
+) -> ExecutionResult {
+    run(tape, head1_start, max_steps, set, None, |_, _, _, _, _| {})
+}
+
+/// Byte size of one packed [`execute_traced`] step record
+pub const TRACE_RECORD_SIZE: usize = 20;
+
+/// Append one packed step record to a trace buffer
+///
+/// Layout (little-endian): `ip: u32, head0: u32, head1: u32, opcode: u8,
+/// mutated: u8, mutated_index: u32, old_value: u8, new_value: u8`.
+/// `mutated_index`/`old_value`/`new_value` are zero when the step didn't
+/// write to the tape.
+fn push_trace_record(
+    trace: &mut Vec<u8>,
+    ip: usize,
+    head0: i32,
+    head1: i32,
+    opcode: u8,
+    mutation: Option<(usize, u8, u8)>,
+) {
+    let before = trace.len();
+
+    trace.extend_from_slice(&(ip as u32).to_le_bytes());
+    trace.extend_from_slice(&(head0 as u32).to_le_bytes());
+    trace.extend_from_slice(&(head1 as u32).to_le_bytes());
+    trace.push(opcode);
+    match mutation {
+        Some((index, old_value, new_value)) => {
+            trace.push(1);
+            trace.extend_from_slice(&(index as u32).to_le_bytes());
+            trace.push(old_value);
+            trace.push(new_value);
+        }
+        None => {
+            trace.push(0);
+            trace.extend_from_slice(&0u32.to_le_bytes());
+            trace.push(0);
+            trace.push(0);
+        }
+    }
+
+    debug_assert_eq!(trace.len() - before, TRACE_RECORD_SIZE);
+}
+
+/// Execute BFF program on tape, recording a per-step trace
+///
+/// Instrumented counterpart to [`execute_with_params`]: instead of only
+/// returning aggregate counters, records one [`TRACE_RECORD_SIZE`]-byte
+/// entry per executed step (see [`push_trace_record`]) so a front end can
+/// scrub through the run for visualization. Shares its dispatch loop with
+/// [`execute_with_set`] via [`run`], so the two can't drift apart.
+///
+/// If `breakpoint_ip` is reached, execution halts before that step runs
+/// with [`HaltReason::Breakpoint`].
+pub fn execute_traced(
+    tape: &mut [u8],
+    head1_start: usize,
+    max_steps: u32,
+    breakpoint_ip: Option<usize>,
+) -> (ExecutionResult, Vec<u8>) {
+    let mut trace = Vec::new();
+    let result = run(
+        tape,
+        head1_start,
+        max_steps,
+        &InstructionSet::paper_default(),
+        breakpoint_ip,
+        |step_ip, head0, head1, byte, mutation| {
+            push_trace_record(&mut trace, step_ip, head0, head1, byte, mutation)
+        },
+    );
+    (result, trace)
+}
+
+/// Shared dispatch loop backing [`execute_with_set`] and [`execute_traced`]
+///
+/// Runs `tape` against `set`, optionally halting early at `breakpoint_ip`
+/// (with [`HaltReason::Breakpoint`]) and calling `on_step` after each
+/// executed step with `(step_ip, head0, head1, opcode, mutation)` — the
+/// instruction's *fetch* position, not wherever `ip` ends up after a
+/// branch, so a trace built from it stays internally consistent even
+/// when a loop actually jumps.
+fn run(
+    tape: &mut [u8],
+    head1_start: usize,
+    max_steps: u32,
+    set: &InstructionSet,
+    breakpoint_ip: Option<usize>,
+    mut on_step: impl FnMut(usize, i32, i32, u8, Option<(usize, u8, u8)>),
+) -> ExecutionResult {
     let size = tape.len();
 
     // Early abort if no instructions
-    if !has_instructions(tape) {
+    if !tape.iter().any(|&b| set.op(b) != Op::Nop) {
         return ExecutionResult {
             steps: 0,
             head0_count: 0,
@@ -139,8 +377,8 @@ pub fn execute_with_params(tape: &mut [u8], head1_start: usize, max_steps: u32)
     }
 
     let mut ip: usize = 0;
-    let mut head0: i32 = 0;  // Starts at beginning (left tape)
-    let mut head1: i32 = head1_start as i32;  // Starts at specified position
+    let mut head0: i32 = 0; // Starts at beginning (left tape)
+    let mut head1: i32 = head1_start as i32; // Starts at specified position
     let mut steps: u32 = 0;
     let mut head0_count: u32 = 0;
     let mut head1_count: u32 = 0;
@@ -149,103 +387,110 @@ pub fn execute_with_params(tape: &mut [u8], head1_start: usize, max_steps: u32)
     let mut loop_count: u32 = 0;
 
     // Wrap any head position to valid tape range
-    let wrap = |h: i32| -> usize {
-        ((h % size as i32) + size as i32) as usize % size
-    };
+    let wrap = |h: i32| -> usize { ((h % size as i32) + size as i32) as usize % size };
+
+    macro_rules! halt {
+        ($reason:expr) => {
+            return ExecutionResult {
+                steps,
+                head0_count,
+                head1_count,
+                math_count,
+                copy_count,
+                loop_count,
+                halt_reason: $reason,
+            }
+        };
+    }
 
     while steps < max_steps && ip < size {
+        if breakpoint_ip == Some(ip) {
+            halt!(HaltReason::Breakpoint);
+        }
+
         steps += 1;
+        let step_ip = ip;
         let byte = tape[ip];
+        let mut mutation: Option<(usize, u8, u8)> = None;
 
-        match byte {
-            instructions::HEAD0_DEC => {
-                head0 -= 1;
-                head0 = wrap(head0) as i32;
+        match set.op(byte) {
+            Op::Head0Dec => {
+                head0 = wrap(head0 - 1) as i32;
                 head0_count += 1;
             }
-            instructions::HEAD0_INC => {
-                head0 += 1;
-                head0 = wrap(head0) as i32;
+            Op::Head0Inc => {
+                head0 = wrap(head0 + 1) as i32;
                 head0_count += 1;
             }
-            instructions::HEAD1_DEC => {
-                head1 -= 1;
-                head1 = wrap(head1) as i32;
+            Op::Head1Dec => {
+                head1 = wrap(head1 - 1) as i32;
                 head1_count += 1;
             }
-            instructions::HEAD1_INC => {
-                head1 += 1;
-                head1 = wrap(head1) as i32;
+            Op::Head1Inc => {
+                head1 = wrap(head1 + 1) as i32;
                 head1_count += 1;
             }
-            instructions::DECREMENT => {
+            Op::Decrement => {
                 let idx = wrap(head0);
-                tape[idx] = tape[idx].wrapping_sub(1);
+                let old = tape[idx];
+                tape[idx] = old.wrapping_sub(1);
+                mutation = Some((idx, old, tape[idx]));
                 math_count += 1;
             }
-            instructions::INCREMENT => {
+            Op::Increment => {
                 let idx = wrap(head0);
-                tape[idx] = tape[idx].wrapping_add(1);
+                let old = tape[idx];
+                tape[idx] = old.wrapping_add(1);
+                mutation = Some((idx, old, tape[idx]));
                 math_count += 1;
             }
-            instructions::COPY_TO_H1 => {
+            Op::CopyToH1 => {
                 let src = wrap(head0);
                 let dst = wrap(head1);
+                let old = tape[dst];
                 tape[dst] = tape[src];
+                mutation = Some((dst, old, tape[dst]));
                 copy_count += 1;
             }
-            instructions::COPY_TO_H0 => {
+            Op::CopyToH0 => {
                 let src = wrap(head1);
                 let dst = wrap(head0);
+                let old = tape[dst];
                 tape[dst] = tape[src];
+                mutation = Some((dst, old, tape[dst]));
                 copy_count += 1;
             }
-            instructions::LOOP_START => {
+            Op::LoopStart => {
                 let idx = wrap(head0);
                 if tape[idx] == 0 {
-                    match find_matching_bracket(tape, ip, 1) {
+                    match find_matching_bracket_with_set(tape, ip, 1, set) {
                         Some(target) => ip = target,
                         None => {
-                            return ExecutionResult {
-                                steps,
-                                head0_count,
-                                head1_count,
-                                math_count,
-                                copy_count,
-                                loop_count,
-                                halt_reason: HaltReason::UnmatchedBracket,
-                            };
+                            on_step(step_ip, head0, head1, byte, None);
+                            halt!(HaltReason::UnmatchedBracket)
                         }
                     }
                 }
             }
-            instructions::LOOP_END => {
+            Op::LoopEnd => {
                 let idx = wrap(head0);
                 loop_count += 1;
                 if tape[idx] != 0 {
-                    match find_matching_bracket(tape, ip, -1) {
-                        Some(target) => {
-                            ip = target;
-                        }
+                    match find_matching_bracket_with_set(tape, ip, -1, set) {
+                        Some(target) => ip = target,
                         None => {
-                            return ExecutionResult {
-                                steps,
-                                head0_count,
-                                head1_count,
-                                math_count,
-                                copy_count,
-                                loop_count,
-                                halt_reason: HaltReason::UnmatchedBracket,
-                            };
+                            on_step(step_ip, head0, head1, byte, None);
+                            halt!(HaltReason::UnmatchedBracket)
                         }
                     }
                 }
             }
-            _ => {
+            Op::Nop => {
                 // No-op: just advance IP
             }
         }
 
+        on_step(step_ip, head0, head1, byte, mutation);
         ip += 1;
     }
 
@@ -266,6 +511,141 @@ pub fn execute_with_params(tape: &mut [u8], head1_start: usize, max_steps: u32)
     }
 }
 
+/// Mnemonic for an instruction byte, or `None` for a data byte.
+fn mnemonic(byte: u8) -> Option<char> {
+    Some(match byte {
+        instructions::HEAD0_DEC => '<',
+        instructions::HEAD0_INC => '>',
+        instructions::HEAD1_DEC => '{',
+        instructions::HEAD1_INC => '}',
+        instructions::DECREMENT => '-',
+        instructions::INCREMENT => '+',
+        instructions::COPY_TO_H1 => '.',
+        instructions::COPY_TO_H0 => ',',
+        instructions::LOOP_START => '[',
+        instructions::LOOP_END => ']',
+        _ => return None,
+    })
+}
+
+/// Render a tape as human-readable BFF assembly
+///
+/// Each line is `<offset>: <mnemonic>`, where data bytes that aren't one
+/// of the ten instructions are rendered as `.byte 0xNN`. `[` and `]`
+/// lines carry a `-> <offset>` arrow to their matching bracket (or
+/// `-> unmatched` if `find_matching_bracket` can't find one), so a reader
+/// can follow loop structure without counting brackets by hand.
+pub fn disassemble(tape: &[u8]) -> String {
+    let mut out = String::with_capacity(tape.len() * 8);
+
+    for (offset, &byte) in tape.iter().enumerate() {
+        match mnemonic(byte) {
+            Some(m) => {
+                out.push_str(&format!("{offset:04}: {m}"));
+                if byte == instructions::LOOP_START || byte == instructions::LOOP_END {
+                    let direction = if byte == instructions::LOOP_START { 1 } else { -1 };
+                    match find_matching_bracket(tape, offset, direction) {
+                        Some(target) => out.push_str(&format!(" -> {target:04}")),
+                        None => out.push_str(" -> unmatched"),
+                    }
+                }
+                out.push('\n');
+            }
+            None => {
+                out.push_str(&format!("{offset:04}: .byte 0x{byte:02X}\n"));
+            }
+        }
+    }
+
+    out
+}
+
+/// Error produced by [`assemble`] when source can't be parsed into a tape
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AssembleError {
+    /// A `.byte` directive wasn't followed by a `0xNN` literal
+    MissingByteLiteral { line: usize },
+    /// A `0xNN` literal was malformed or out of range
+    InvalidByteLiteral { line: usize, token: String },
+    /// A token wasn't a known mnemonic or a `.byte` directive (a bare
+    /// `0xNN` literal without `.byte` is also rejected)
+    UnknownToken { line: usize, token: String },
+}
+
+impl std::fmt::Display for AssembleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AssembleError::MissingByteLiteral { line } => {
+                write!(f, "line {line}: `.byte` with no following 0xNN literal")
+            }
+            AssembleError::InvalidByteLiteral { line, token } => {
+                write!(f, "line {line}: invalid byte literal `{token}`")
+            }
+            AssembleError::UnknownToken { line, token } => {
+                write!(f, "line {line}: unknown token `{token}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AssembleError {}
+
+/// Parse BFF assembly (as produced by [`disassemble`]) back into a tape
+///
+/// Accepts one instruction per line, optionally prefixed with an
+/// `<offset>:` label and suffixed with a ` -> <offset>` bracket
+/// annotation (both are ignored, so `disassemble`'s output round-trips).
+/// Data bytes are written as `.byte 0xNN`. Blank lines and `;` comments
+/// are ignored, so hand-written seed programs can be formatted freely.
+pub fn assemble(src: &str) -> Result<Vec<u8>, AssembleError> {
+    let mut tape = Vec::new();
+
+    for (line_no, raw_line) in src.lines().enumerate() {
+        let line_no = line_no + 1;
+
+        // Strip a `;` comment, then a leading `<offset>:` label.
+        let line = raw_line.split(';').next().unwrap_or("");
+        let line = match line.split_once(':') {
+            Some((label, rest)) if label.trim().chars().all(|c| c.is_ascii_hexdigit()) => rest,
+            _ => line,
+        };
+
+        let mut tokens = line.split_whitespace().peekable();
+        while let Some(token) = tokens.next() {
+            if token == "->" {
+                tokens.next(); // skip the bracket-match target
+                continue;
+            }
+
+            if token == ".byte" {
+                let literal = tokens.next().ok_or(AssembleError::MissingByteLiteral { line: line_no })?;
+                let hex = literal.strip_prefix("0x").ok_or_else(|| AssembleError::InvalidByteLiteral {
+                    line: line_no,
+                    token: literal.to_string(),
+                })?;
+                let byte = u8::from_str_radix(hex, 16).map_err(|_| AssembleError::InvalidByteLiteral {
+                    line: line_no,
+                    token: literal.to_string(),
+                })?;
+                tape.push(byte);
+                continue;
+            }
+
+            if token.chars().count() == 1 {
+                let ch = token.chars().next().unwrap();
+                if is_instruction(ch as u8) {
+                    tape.push(ch as u8);
+                    continue;
+                }
+            }
+
+            return Err(AssembleError::UnknownToken { line: line_no, token: token.to_string() });
+        }
+    }
+
+    Ok(tape)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -294,4 +674,133 @@ mod tests {
         assert_eq!(result.halt_reason, HaltReason::NoInstructions);
         assert_eq!(result.steps, 0);
     }
+
+    #[test]
+    fn test_execute_with_set_default_matches_execute_with_params() {
+        let mut set_tape = vec![b'+', b'+', 0, 0];
+        let mut plain_tape = set_tape.clone();
+
+        let set_result = execute_with_set(&mut set_tape, 2, MAX_STEPS, &InstructionSet::paper_default());
+        let plain_result = execute_with_params(&mut plain_tape, 2, MAX_STEPS);
+
+        assert_eq!(set_tape, plain_tape);
+        assert_eq!(set_result.steps, plain_result.steps);
+        assert_eq!(set_result.math_count, plain_result.math_count);
+        assert_eq!(set_result.halt_reason, plain_result.halt_reason);
+    }
+
+    #[test]
+    fn test_execute_with_set_custom_byte_mapping() {
+        // Remap 'x' to increment and 'y' to decrement, ignoring the usual '+'/'-'.
+        let mut table = [10u8; 256]; // default everything to Nop
+        table[b'x' as usize] = Op::Increment as u8;
+        table[b'y' as usize] = Op::Decrement as u8;
+        let set = InstructionSet::from_table(&table);
+
+        let mut tape = vec![b'x', b'x', b'y', 0];
+        let result = execute_with_set(&mut tape, 3, MAX_STEPS, &set);
+
+        assert_eq!(result.math_count, 3);
+        assert_eq!(tape[0], b'x' + 1); // two increments then a decrement on tape[0]
+    }
+
+    #[test]
+    fn test_disassemble_marks_data_and_brackets() {
+        let text = disassemble(b"[+]\x01");
+        assert!(text.contains("0000: [ -> 0002"));
+        assert!(text.contains("0001: +"));
+        assert!(text.contains("0002: ] -> 0000"));
+        assert!(text.contains("0003: .byte 0x01"));
+    }
+
+    #[test]
+    fn test_disassemble_assemble_roundtrip() {
+        let tape = vec![b'[', b'+', b'-', b']', 0x01, 0xFF, b'<', b'>'];
+        let text = disassemble(&tape);
+        let reassembled = assemble(&text).unwrap();
+        assert_eq!(reassembled, tape);
+    }
+
+    #[test]
+    fn test_assemble_rejects_unknown_token() {
+        assert_eq!(
+            assemble("nope"),
+            Err(AssembleError::UnknownToken { line: 1, token: "nope".to_string() })
+        );
+    }
+
+    #[test]
+    fn test_execute_traced_matches_execute_with_params() {
+        let mut traced_tape = vec![b'+', b'+', 0, 0];
+        let mut plain_tape = traced_tape.clone();
+
+        let (traced_result, trace) = execute_traced(&mut traced_tape, 2, MAX_STEPS, None);
+        let plain_result = execute_with_params(&mut plain_tape, 2, MAX_STEPS);
+
+        assert_eq!(traced_tape, plain_tape);
+        assert_eq!(traced_result.steps, plain_result.steps);
+        assert_eq!(traced_result.math_count, plain_result.math_count);
+        assert_eq!(trace.len(), traced_result.steps as usize * TRACE_RECORD_SIZE);
+    }
+
+    #[test]
+    fn test_execute_traced_records_mutation() {
+        let mut tape = vec![b'+', 0, 0, 0];
+        let (result, trace) = execute_traced(&mut tape, 2, MAX_STEPS, None);
+
+        // 4 steps (one per tape byte, including no-ops), only the first mutates.
+        assert_eq!(result.steps, 4);
+        assert_eq!(trace.len(), 4 * TRACE_RECORD_SIZE);
+        assert_eq!(u32::from_le_bytes(trace[0..4].try_into().unwrap()), 0); // ip
+        assert_eq!(trace[12], b'+'); // opcode
+        assert_eq!(trace[13], 1); // mutated flag
+        assert_eq!(u32::from_le_bytes(trace[14..18].try_into().unwrap()), 0); // index
+        assert_eq!(trace[18], b'+'); // old value
+        assert_eq!(trace[19], b'+' + 1); // new value
+
+        // Subsequent steps are no-op data bytes: no mutation recorded.
+        assert_eq!(trace[20 + 13], 0);
+    }
+
+    #[test]
+    fn test_execute_traced_breakpoint_halts_early() {
+        let mut tape = vec![b'+', b'+', b'+', 0];
+        let (result, trace) = execute_traced(&mut tape, 3, MAX_STEPS, Some(1));
+
+        assert_eq!(result.halt_reason, HaltReason::Breakpoint);
+        assert_eq!(result.steps, 1);
+        assert_eq!(trace.len(), TRACE_RECORD_SIZE);
+        assert_eq!(tape[0], b','); // first '+' executed, second never reached
+    }
+
+    #[test]
+    fn test_execute_traced_branch_records_fetch_ip_not_jump_target() {
+        // head0 starts on the leading zero, so `[` at ip=1 takes the
+        // skip-forward branch straight to the matching `]` at ip=3.
+        let mut tape = vec![0, b'[', b'-', b']', b'+'];
+        let (result, trace) = execute_traced(&mut tape, 0, MAX_STEPS, None);
+
+        assert_eq!(result.loop_count, 0); // `]` is jumped over, never executed
+        assert_eq!(trace.len(), 3 * TRACE_RECORD_SIZE);
+
+        let record = |i: usize| -> (u32, u8) {
+            let base = i * TRACE_RECORD_SIZE;
+            (
+                u32::from_le_bytes(trace[base..base + 4].try_into().unwrap()),
+                trace[base + 12],
+            )
+        };
+
+        assert_eq!(record(0), (0, 0)); // leading data byte
+        assert_eq!(record(1), (1, b'[')); // fetched at ip=1, not the ip=3 jump target
+        assert_eq!(record(2), (4, b'+')); // `]` itself is skipped, never fetched
+    }
+
+    #[test]
+    fn test_assemble_rejects_bad_byte_literal() {
+        assert_eq!(
+            assemble(".byte xyz"),
+            Err(AssembleError::InvalidByteLiteral { line: 1, token: "xyz".to_string() })
+        );
+    }
 }
@@ -1,5 +1,110 @@
 //! Compression/entropy utilities for Turing Soup
 
+/// Fixed deflate level used for all NCD compression calls.
+///
+/// Matches the level `kolmogorov_estimate` uses, so NCD scores stay
+/// comparable to the existing per-region complexity estimates.
+const NCD_DEFLATE_LEVEL: u8 = 6;
+
+/// Compressed length of `data` in bytes, used as the `C(x)` term in NCD.
+fn compressed_len(data: &[u8]) -> usize {
+    miniz_oxide::deflate::compress_to_vec(data, NCD_DEFLATE_LEVEL).len()
+}
+
+/// Reusable streaming deflate compressor for scoring many regions
+///
+/// A fresh [`miniz_oxide::deflate::compress_to_vec`] call allocates both
+/// the compressor's internal hash-chain tables and its output buffer, which
+/// is wasteful when scoring thousands of equally-sized regions per soup
+/// generation. `Compressor` owns both and reuses them across calls via
+/// [`Compressor::compressed_len`], resetting only the compression state
+/// (not the allocations) between inputs.
+pub struct Compressor {
+    inner: miniz_oxide::deflate::core::CompressorOxide,
+    scratch: Vec<u8>,
+}
+
+impl Compressor {
+    /// Create a compressor at [`NCD_DEFLATE_LEVEL`], matching the one-shot
+    /// helpers in this module.
+    pub fn new() -> Self {
+        Compressor {
+            inner: miniz_oxide::deflate::core::CompressorOxide::with_format_and_level(
+                miniz_oxide::DataFormat::Raw,
+                miniz_oxide::deflate::CompressionLevel::DefaultLevel,
+            ),
+            scratch: Vec::new(),
+        }
+    }
+
+    /// Compressed length of `data` in bytes, reusing this compressor's
+    /// scratch buffer and hash tables instead of allocating fresh ones.
+    pub fn compressed_len(&mut self, data: &[u8]) -> usize {
+        use miniz_oxide::deflate::core::{compress, TDEFLFlush, TDEFLStatus};
+
+        self.inner.reset();
+        let needed = data.len() / 2 + 64;
+        if self.scratch.len() < needed {
+            self.scratch.resize(needed, 0);
+        }
+
+        let mut input = data;
+        let mut out_pos = 0;
+        loop {
+            let (status, bytes_in, bytes_out) =
+                compress(&mut self.inner, input, &mut self.scratch[out_pos..], TDEFLFlush::Finish);
+            out_pos += bytes_out;
+
+            match status {
+                TDEFLStatus::Done => break,
+                TDEFLStatus::Okay if bytes_in <= input.len() => {
+                    input = &input[bytes_in..];
+                    if self.scratch.len().saturating_sub(out_pos) < 30 {
+                        self.scratch.resize(self.scratch.len() * 2, 0);
+                    }
+                }
+                _ => panic!("Bug! Unexpectedly failed to compress!"),
+            }
+        }
+
+        out_pos
+    }
+}
+
+impl Default for Compressor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Normalized Compression Distance between two byte regions
+///
+/// `NCD(x, y) = (C(xy) - min(C(x), C(y))) / max(C(x), C(y))`, where `C`
+/// is deflate-compressed length. Roughly in `[0, 1]`: near 0 means one
+/// region compresses almost as well as a copy of the other (a likely
+/// replicator), near 1 means the regions are unrelated.
+pub fn ncd(x: &[u8], y: &[u8]) -> f64 {
+    if x.is_empty() || y.is_empty() {
+        return 1.0;
+    }
+
+    let cx = compressed_len(x);
+    let cy = compressed_len(y);
+    let max_c = cx.max(cy);
+
+    if max_c == 0 {
+        return 0.0;
+    }
+
+    let mut xy = Vec::with_capacity(x.len() + y.len());
+    xy.extend_from_slice(x);
+    xy.extend_from_slice(y);
+    let cxy = compressed_len(&xy);
+
+    let distance = (cxy as f64 - cx.min(cy) as f64) / max_c as f64;
+    distance.max(0.0)
+}
+
 /// Calculate Shannon entropy of a byte array (bits per byte)
 pub fn shannon_entropy(data: &[u8]) -> f64 {
     if data.is_empty() {
@@ -42,4 +147,45 @@ mod tests {
         let entropy = shannon_entropy(&data);
         assert!((entropy - 8.0).abs() < 0.001);
     }
+
+    #[test]
+    fn test_ncd_empty_is_one() {
+        assert_eq!(ncd(b"", b"abc"), 1.0);
+        assert_eq!(ncd(b"abc", b""), 1.0);
+        assert_eq!(ncd(b"", b""), 1.0);
+    }
+
+    #[test]
+    fn test_ncd_identical_is_near_zero() {
+        let data = [b'+', b'-', b'<', b'>'].repeat(16);
+        let distance = ncd(&data, &data);
+        assert!(distance < 0.2, "expected near-zero NCD, got {distance}");
+    }
+
+    #[test]
+    fn test_ncd_unrelated_is_larger() {
+        let a = vec![0u8; 64];
+        let b: Vec<u8> = (0..64).collect();
+        let same = ncd(&a, &a);
+        let different = ncd(&a, &b);
+        assert!(different > same);
+    }
+
+    #[test]
+    fn test_compressor_matches_one_shot() {
+        let mut compressor = Compressor::new();
+        let data = b"hello hello hello hello world world".repeat(5);
+        assert_eq!(compressor.compressed_len(&data), compressed_len(&data));
+    }
+
+    #[test]
+    fn test_compressor_reused_across_varied_inputs() {
+        let mut compressor = Compressor::new();
+        let small = b"abc";
+        let large = vec![b'x'; 4096];
+
+        assert_eq!(compressor.compressed_len(small), compressed_len(small));
+        assert_eq!(compressor.compressed_len(&large), compressed_len(&large));
+        assert_eq!(compressor.compressed_len(small), compressed_len(small));
+    }
 }
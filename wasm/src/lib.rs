@@ -88,6 +88,90 @@ pub fn execute_pair(
     output
 }
 
+/// Disassemble a tape into human-readable BFF assembly
+#[wasm_bindgen]
+pub fn disassemble(tape: &[u8]) -> String {
+    bff::disassemble(tape)
+}
+
+/// Assemble BFF source (as produced by `disassemble`) into a tape
+#[wasm_bindgen]
+pub fn assemble(src: &str) -> Result<Vec<u8>, JsValue> {
+    bff::assemble(src).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/// Execute BFF program on a tape, recording a per-step trace
+///
+/// Instrumented counterpart to `execute_tape` / `execute_pair` for
+/// visualization front ends: instead of only aggregate counters, it
+/// records one `bff::TRACE_RECORD_SIZE`-byte entry per executed step
+/// (see `bff::execute_traced`) so a UI can scrub through the run.
+/// `max_steps == 0` means the default `bff::MAX_STEPS`; pass
+/// `breakpoint_ip` to halt early with `HaltReason::Breakpoint` once the
+/// instruction pointer reaches it.
+///
+/// Returns the 28-byte packed `ExecutionResult` (same layout as
+/// `execute_pair`) followed by the trace records.
+#[wasm_bindgen]
+pub fn execute_tape_traced(
+    tape: &mut [u8],
+    head1_start: usize,
+    max_steps: u32,
+    breakpoint_ip: Option<usize>,
+) -> Vec<u8> {
+    let max_steps = if max_steps == 0 { bff::MAX_STEPS } else { max_steps };
+    let (result, trace) = bff::execute_traced(tape, head1_start, max_steps, breakpoint_ip);
+
+    let mut output = Vec::with_capacity(28 + trace.len());
+    output.extend_from_slice(&result.steps.to_le_bytes());
+    output.extend_from_slice(&result.head0_count.to_le_bytes());
+    output.extend_from_slice(&result.head1_count.to_le_bytes());
+    output.extend_from_slice(&result.math_count.to_le_bytes());
+    output.extend_from_slice(&result.copy_count.to_le_bytes());
+    output.extend_from_slice(&result.loop_count.to_le_bytes());
+    output.extend_from_slice(&(result.halt_reason as u32).to_le_bytes());
+    output.extend_from_slice(&trace);
+
+    output
+}
+
+/// Execute BFF program on a tape using a custom instruction set
+///
+/// `op_table` must be exactly 256 bytes: `op_table[byte]` is the op
+/// discriminant (`0`-`9`, see `bff::Op`) that tape byte performs, with
+/// `10` or anything else treated as a no-op data byte. Lets a soup run
+/// experiment with different byte mappings without recompiling; only the
+/// ten standard operations can be mapped (no extra heads or relative-copy
+/// ops). `execute_tape`
+/// stays a thin wrapper over the paper's default set. `head1_start` and
+/// `max_steps` are configurable for the same reason `execute_tape_traced`
+/// exposes them: a custom set may want head1 placed elsewhere, or need
+/// more than `bff::MAX_STEPS` to do anything interesting. Pass `0` for
+/// `max_steps` to use the default.
+#[wasm_bindgen]
+pub fn execute_tape_with_set(
+    tape: &mut [u8],
+    op_table: &[u8],
+    head1_start: usize,
+    max_steps: u32,
+) -> Result<ExecutionResult, JsValue> {
+    let table: &[u8; 256] = op_table
+        .try_into()
+        .map_err(|_| JsValue::from_str("op_table must be exactly 256 bytes"))?;
+    let max_steps = if max_steps == 0 { bff::MAX_STEPS } else { max_steps };
+    let set = bff::InstructionSet::from_table(table);
+    let result = bff::execute_with_set(tape, head1_start, max_steps, &set);
+    Ok(ExecutionResult {
+        steps: result.steps,
+        head0_count: result.head0_count,
+        head1_count: result.head1_count,
+        math_count: result.math_count,
+        copy_count: result.copy_count,
+        loop_count: result.loop_count,
+        halt_reason: result.halt_reason as u8,
+    })
+}
+
 /// Check if a region contains any BFF instructions
 #[wasm_bindgen]
 pub fn has_instructions(data: &[u8]) -> bool {
@@ -110,12 +194,48 @@ pub fn kolmogorov_estimate(data: &[u8]) -> f64 {
     (compressed.len() as f64 * 8.0) / data.len() as f64
 }
 
+/// Normalized Compression Distance between two byte regions
+///
+/// ~0 means one region is nearly a compressed copy of the other (a
+/// successful replicator), ~1 means the regions are unrelated.
+#[wasm_bindgen]
+pub fn ncd(x: &[u8], y: &[u8]) -> f64 {
+    compression::ncd(x, y)
+}
+
+/// Default number of pairs handed to a single worker task at a time
+///
+/// Chosen to keep per-task overhead (rayon scheduling, when the `threads`
+/// feature is enabled) small relative to the work of executing a chunk of
+/// pairs, without making any single task so large it stalls the pool.
+pub const DEFAULT_BATCH_CHUNK_SIZE: usize = 64;
+
+/// Parse the little-endian `(slot_a, slot_b)` u32 pair at index `i`
+fn read_pair(pairs: &[u8], i: usize) -> (usize, usize) {
+    let offset = i * 8; // 2 x u32
+    let slot_a = u32::from_le_bytes([
+        pairs[offset],
+        pairs[offset + 1],
+        pairs[offset + 2],
+        pairs[offset + 3],
+    ]) as usize;
+    let slot_b = u32::from_le_bytes([
+        pairs[offset + 4],
+        pairs[offset + 5],
+        pairs[offset + 6],
+        pairs[offset + 7],
+    ]) as usize;
+    (slot_a, slot_b)
+}
+
 /// Run a batch of pair executions
 ///
 /// Input format: pairs as [slot_a_lo, slot_a_hi, slot_a_extra1, slot_a_extra2, slot_b_lo, slot_b_hi, slot_b_extra1, slot_b_extra2, ...]
 /// (8 bytes per pair: 4 for slot_a as u32, 4 for slot_b as u32)
 ///
-/// Returns concatenated results for each pair
+/// Returns concatenated results for each pair, using `DEFAULT_BATCH_CHUNK_SIZE`
+/// as the work-splitting granularity. See `execute_batch_chunked` for the
+/// underlying implementation and its threading/memory model notes.
 #[wasm_bindgen]
 pub fn execute_batch(
     soup: &[u8],
@@ -123,17 +243,140 @@ pub fn execute_batch(
     region_size: usize,
     head1_offset: usize,
     max_steps: u32,
+) -> Vec<u8> {
+    execute_batch_chunked(
+        soup,
+        pairs,
+        region_size,
+        head1_offset,
+        max_steps,
+        DEFAULT_BATCH_CHUNK_SIZE,
+    )
+}
+
+/// Run a batch of pair executions, splitting work across a worker pool
+///
+/// Identical contract to `execute_batch`, but lets callers tune
+/// `chunk_size`: the number of pairs each worker task computes before
+/// yielding its slice of results back. Each pair execution only reads
+/// `soup` (shared, read-only for the whole call) and writes into its own
+/// disjoint `result_size`-byte slice of the pre-sized output buffer, so
+/// the byte layout of `output` is identical and deterministic regardless
+/// of how many threads process it or in what order chunks complete.
+///
+/// With the `threads` feature enabled (requires the `wasm-bindgen-rayon`
+/// thread pool to already be initialized on the JS side, which in turn
+/// requires the module's memory to be a `SharedArrayBuffer`), chunks run
+/// concurrently via rayon. Otherwise this falls back to the same
+/// single-threaded loop `execute_batch` has always used.
+#[wasm_bindgen]
+pub fn execute_batch_chunked(
+    soup: &[u8],
+    pairs: &[u8],
+    region_size: usize,
+    head1_offset: usize,
+    max_steps: u32,
+    chunk_size: usize,
 ) -> Vec<u8> {
     let pair_size = 8; // 2 x u32
     let num_pairs = pairs.len() / pair_size;
     let result_size = 28 + region_size * 2; // 7 u32 stats + tape
+    let chunk_size = chunk_size.max(1);
+
+    let mut output = vec![0u8; num_pairs * result_size];
+
+    let fill_chunk = |chunk_start: usize, out_chunk: &mut [u8]| {
+        for local in 0..(out_chunk.len() / result_size) {
+            let (slot_a, slot_b) = read_pair(pairs, chunk_start + local);
+            let result = execute_pair(soup, slot_a, slot_b, region_size, head1_offset, max_steps);
+            out_chunk[local * result_size..(local + 1) * result_size].copy_from_slice(&result);
+        }
+    };
+
+    #[cfg(feature = "threads")]
+    {
+        use rayon::prelude::*;
+        output
+            .par_chunks_mut(result_size * chunk_size)
+            .enumerate()
+            .for_each(|(chunk_idx, out_chunk)| fill_chunk(chunk_idx * chunk_size, out_chunk));
+    }
+
+    #[cfg(not(feature = "threads"))]
+    {
+        for (chunk_idx, out_chunk) in output.chunks_mut(result_size * chunk_size).enumerate() {
+            fill_chunk(chunk_idx * chunk_size, out_chunk);
+        }
+    }
+
+    output
+}
 
-    let mut output = Vec::with_capacity(num_pairs * result_size);
+/// Score the Kolmogorov complexity (compressed bits per byte) of many
+/// soup regions in one call
+///
+/// `offsets` is a list of little-endian u32 region start offsets (4 bytes
+/// each); each region is `region_size` bytes, wrapping around `soup` like
+/// `execute_pair`'s region extraction. Reuses a single
+/// `compression::Compressor` across every region instead of allocating a
+/// fresh compressor per call like `kolmogorov_estimate`, so a whole
+/// population can be scored in a single crossing of the WASM boundary.
+#[wasm_bindgen]
+pub fn kolmogorov_estimate_batch(soup: &[u8], offsets: &[u8], region_size: usize) -> Vec<f64> {
+    let num_regions = offsets.len() / 4;
+    let mut compressor = compression::Compressor::new();
+    let mut region = Vec::with_capacity(region_size);
+
+    (0..num_regions)
+        .map(|i| {
+            let off = i * 4;
+            let start = u32::from_le_bytes([
+                offsets[off],
+                offsets[off + 1],
+                offsets[off + 2],
+                offsets[off + 3],
+            ]) as usize;
+
+            region.clear();
+            for j in 0..region_size {
+                region.push(soup[(start + j) % soup.len()]);
+            }
+
+            if region.is_empty() {
+                0.0
+            } else {
+                (compressor.compressed_len(&region) as f64 * 8.0) / region.len() as f64
+            }
+        })
+        .collect()
+}
+
+/// Run a batch of pair executions and score each pair's NCD before and after
+///
+/// For every pair this extracts region A and region B from `soup` (the
+/// "before" state), executes them together, then splits the resulting
+/// combined tape back into region A and region B (the "after" state) and
+/// computes the NCD between A and B in each state. This lets callers decide
+/// replication/acceptance without re-crossing the WASM boundary per pair.
+///
+/// Returns concatenated `[ncd_before, ncd_after]` as little-endian f64
+/// pairs (16 bytes per input pair).
+#[wasm_bindgen]
+pub fn execute_batch_ncd(
+    soup: &[u8],
+    pairs: &[u8],
+    region_size: usize,
+    head1_offset: usize,
+    max_steps: u32,
+) -> Vec<u8> {
+    let pair_size = 8; // 2 x u32
+    let num_pairs = pairs.len() / pair_size;
+
+    let mut output = Vec::with_capacity(num_pairs * 16);
 
     for i in 0..num_pairs {
         let offset = i * pair_size;
 
-        // Parse slot indices as little-endian u32
         let slot_a = u32::from_le_bytes([
             pairs[offset],
             pairs[offset + 1],
@@ -148,8 +391,20 @@ pub fn execute_batch(
             pairs[offset + 7],
         ]) as usize;
 
+        let mut region_a = Vec::with_capacity(region_size);
+        let mut region_b = Vec::with_capacity(region_size);
+        for i in 0..region_size {
+            region_a.push(soup[(slot_a + i) % soup.len()]);
+            region_b.push(soup[(slot_b + i) % soup.len()]);
+        }
+        let ncd_before = compression::ncd(&region_a, &region_b);
+
         let result = execute_pair(soup, slot_a, slot_b, region_size, head1_offset, max_steps);
-        output.extend_from_slice(&result);
+        let combined = &result[28..];
+        let ncd_after = compression::ncd(&combined[..region_size], &combined[region_size..]);
+
+        output.extend_from_slice(&ncd_before.to_le_bytes());
+        output.extend_from_slice(&ncd_after.to_le_bytes());
     }
 
     output